@@ -0,0 +1,320 @@
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+// An alternative backend for the deque core of `fourth`'s `List<T>`, trading
+// the `Rc<RefCell<Node<T>>>` representation for raw `NonNull` links owned via
+// `Box::into_raw`/`Box::from_raw`. That drops the per-operation refcount bumps
+// and runtime borrow checks, and — crucially — lets `peek_*` hand out honest
+// `&T`/`&mut T` and `iter_mut` expose real `&mut T` instead of `RefMut` guards.
+// It covers push/pop/peek at both ends, `len`/`is_empty`, `Default` and the
+// `into_iter`/`iter`/`iter_mut` iterators only; the cursors, `remove`/
+// `contains`/`remove_at`, `append`/`split_off` and the other collection traits
+// of `fourth` are not provided here.
+pub struct List<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    // We logically own the nodes behind the pointers, so tell the compiler so
+    // it gets the drop-check and variance right.
+    _boo: PhantomData<Box<Node<T>>>
+}
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+    prev: Link<T>
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List { head: None, tail: None, len: 0, _boo: PhantomData }
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        // SAFETY: the node is freshly boxed, so the pointer is non-null and
+        // uniquely owned until we store it in the chain.
+        unsafe {
+            let new = NonNull::new_unchecked(Box::into_raw(Box::new(Node {
+                elem,
+                next: None,
+                prev: None
+            })));
+            match self.head {
+                Some(old_head) => {
+                    (*old_head.as_ptr()).prev = Some(new);
+                    (*new.as_ptr()).next = Some(old_head);
+                }
+                None => self.tail = Some(new)
+            }
+            self.head = Some(new);
+            self.len += 1;
+        }
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        // SAFETY: as in `push_front`, the node is freshly boxed and ours alone.
+        unsafe {
+            let new = NonNull::new_unchecked(Box::into_raw(Box::new(Node {
+                elem,
+                next: None,
+                prev: None
+            })));
+            match self.tail {
+                Some(old_tail) => {
+                    (*old_tail.as_ptr()).next = Some(new);
+                    (*new.as_ptr()).prev = Some(old_tail);
+                }
+                None => self.head = Some(new)
+            }
+            self.tail = Some(new);
+            self.len += 1;
+        }
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        // SAFETY: a linked node was produced by `Box::into_raw`, so reclaiming
+        // it with `Box::from_raw` is sound and hands ownership back to us.
+        unsafe {
+            self.head.map(|node| {
+                let boxed = Box::from_raw(node.as_ptr());
+                self.head = boxed.next;
+                match self.head {
+                    Some(new_head) => (*new_head.as_ptr()).prev = None,
+                    None => self.tail = None
+                }
+                self.len -= 1;
+                boxed.elem
+            })
+        }
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        // SAFETY: symmetric to `pop_front`.
+        unsafe {
+            self.tail.map(|node| {
+                let boxed = Box::from_raw(node.as_ptr());
+                self.tail = boxed.prev;
+                match self.tail {
+                    Some(new_tail) => (*new_tail.as_ptr()).next = None,
+                    None => self.head = None
+                }
+                self.len -= 1;
+                boxed.elem
+            })
+        }
+    }
+
+    pub fn peek_front(&self) -> Option<&T> {
+        // SAFETY: the borrow of `self` keeps the node alive for as long as the
+        // returned reference, and no other access can occur meanwhile.
+        unsafe { self.head.map(|node| &(*node.as_ptr()).elem) }
+    }
+
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `&mut self` guarantees unique access for the reference's life.
+        unsafe { self.head.map(|node| &mut (*node.as_ptr()).elem) }
+    }
+
+    pub fn peek_back(&self) -> Option<&T> {
+        // SAFETY: see `peek_front`.
+        unsafe { self.tail.map(|node| &(*node.as_ptr()).elem) }
+    }
+
+    pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: see `peek_front_mut`.
+        unsafe { self.tail.map(|node| &mut (*node.as_ptr()).elem) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        // Drain through pop_front so every boxed node is reclaimed exactly once.
+        while self.pop_front().is_some() {}
+    }
+}
+
+pub struct IntoIter<T>(List<T>);
+
+pub struct Iter<'a, T> {
+    next: Link<T>,
+    next_back: Link<T>,
+    len: usize,
+    _boo: PhantomData<&'a T>
+}
+
+pub struct IterMut<'a, T> {
+    next: Link<T>,
+    next_back: Link<T>,
+    len: usize,
+    _boo: PhantomData<&'a mut T>
+}
+
+impl<T> List<T> {
+    pub fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+
+    pub fn iter(&self) -> Iter<T> {
+        Iter { next: self.head, next_back: self.tail, len: self.len, _boo: PhantomData }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut { next: self.head, next_back: self.tail, len: self.len, _boo: PhantomData }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: `len` tracks how many live nodes remain between the two ends,
+        // so a non-zero count means `next` points at a node still owned by the
+        // borrowed list.
+        self.next.map(|node| unsafe {
+            self.len -= 1;
+            self.next = (*node.as_ptr()).next;
+            &(*node.as_ptr()).elem
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: see `next`.
+        self.next_back.map(|node| unsafe {
+            self.len -= 1;
+            self.next_back = (*node.as_ptr()).prev;
+            &(*node.as_ptr()).elem
+        })
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: each live node is yielded at most once, so the `&mut` handed
+        // out never aliases a reference still in flight.
+        self.next.map(|node| unsafe {
+            self.len -= 1;
+            self.next = (*node.as_ptr()).next;
+            &mut (*node.as_ptr()).elem
+        })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        // SAFETY: see `next`.
+        self.next_back.map(|node| unsafe {
+            self.len -= 1;
+            self.next_back = (*node.as_ptr()).prev;
+            &mut (*node.as_ptr()).elem
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+
+    #[test]
+    fn basics() {
+        let mut list = List::new();
+
+        // Check empty list behaves right
+        assert_eq!(list.pop_front(), None);
+
+        // Populate list
+        list.push_front(1);
+        list.push_front(2);
+        list.push_front(3);
+        list.push_back(4);
+        list.push_back(5);
+
+        // Check normal removal
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_back(), Some(5));
+        assert_eq!(list.pop_back(), Some(4));
+
+        // Check exhaustion
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn peek() {
+        let mut list = List::new();
+        assert!(list.peek_front().is_none());
+        assert!(list.peek_back().is_none());
+        list.push_front(1); list.push_front(2); list.push_front(3);
+        assert_eq!(list.peek_front(), Some(&3));
+        assert_eq!(list.peek_back(), Some(&1));
+        *list.peek_front_mut().unwrap() = 10;
+        assert_eq!(list.peek_front(), Some(&10));
+    }
+
+    #[test]
+    fn into_iter() {
+        let mut list = List::new();
+        list.push_back(1); list.push_back(2); list.push_back(3);
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = List::new();
+        list.push_back(1); list.push_back(2); list.push_back(3);
+        for elem in list.iter_mut() {
+            *elem *= 10;
+        }
+        assert_eq!(list.peek_front(), Some(&10));
+        let sum: i32 = list.iter().sum();
+        assert_eq!(sum, 60);
+    }
+}
@@ -1,9 +1,14 @@
 use std::rc::Rc;
 use std::cell::{Ref, RefCell, RefMut};
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 
 pub struct List<T> {
     head: Link<T>,
-    tail: Link<T>
+    tail: Link<T>,
+    len: usize
 }
 
 type Link<T> = Option<Rc<RefCell<Node<T>>>>;
@@ -14,6 +19,19 @@ pub struct Node<T> {
     prev: Link<T>
 }
 
+// Counts the nodes reachable from `head` by following `next`. Used when a
+// cursor split severs the chain at a position we didn't reach by index, so the
+// two halves' `len` fields have to be recomputed.
+fn count_nodes<T>(head: &Link<T>) -> usize {
+    let mut count = 0;
+    let mut current = head.clone();
+    while let Some(node) = current {
+        count += 1;
+        current = node.borrow().next.clone();
+    }
+    count
+}
+
 impl<T> Node<T> {
     fn new(elem: T) -> Rc<RefCell<Self>> {
         Rc::new(RefCell::new(Self {
@@ -26,7 +44,15 @@ impl<T> Node<T> {
 
 impl<T> List<T> {
     pub fn new() -> Self {
-        List { head: None, tail: None }
+        List { head: None, tail: None, len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
     pub fn push_front(&mut self, elem: T) {
@@ -46,6 +72,7 @@ impl<T> List<T> {
                 // total +2 new_head
             }
         }
+        self.len += 1;
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
@@ -65,6 +92,7 @@ impl<T> List<T> {
                     // total: -2 old_head
                 }
             }
+            self.len -= 1;
             Rc::try_unwrap(old_head).ok().unwrap().into_inner().elem
         })
     }
@@ -100,6 +128,7 @@ impl<T> List<T> {
                 // total +2 new_tail
             }
         }
+        self.len += 1;
     }
 
     pub fn pop_back(&mut self) -> Option<T> {
@@ -119,6 +148,7 @@ impl<T> List<T> {
                     // total: -2 old_tail
                 }
             }
+            self.len -= 1;
             Rc::try_unwrap(old_tail).ok().unwrap().into_inner().elem
         })
     }
@@ -136,6 +166,125 @@ impl<T> List<T> {
             RefMut::map(node.borrow_mut(), |node| &mut node.elem)
         })
     }
+
+    // Unlinks `node` from the chain, rewiring its neighbours and fixing up
+    // self.head/self.tail for the four cases (head, tail, both, middle), then
+    // extracts the element. The caller must pass the only surviving external
+    // `Rc` to `node`; once the neighbours drop their links try_unwrap succeeds.
+    fn remove_node(&mut self, node: Rc<RefCell<Node<T>>>) -> T {
+        let prev = node.borrow_mut().prev.take();
+        let next = node.borrow_mut().next.take();
+        match (prev, next) {
+            (Some(prev), Some(next)) => {
+                prev.borrow_mut().next = Some(next.clone());
+                next.borrow_mut().prev = Some(prev);
+            }
+            (Some(prev), None) => {
+                prev.borrow_mut().next = None;
+                self.tail = Some(prev);
+            }
+            (None, Some(next)) => {
+                next.borrow_mut().prev = None;
+                self.head = Some(next);
+            }
+            (None, None) => {
+                self.head = None;
+                self.tail = None;
+            }
+        }
+        self.len -= 1;
+        Rc::try_unwrap(node).ok().unwrap().into_inner().elem
+    }
+
+    pub fn append(&mut self, other: &mut List<T>) {
+        // Splice other's chain onto our tail in O(1) by joining the two
+        // boundary nodes, then adopt other's tail and leave it empty.
+        match (self.tail.take(), other.head.take()) {
+            (Some(self_tail), Some(other_head)) => {
+                self_tail.borrow_mut().next = Some(other_head.clone());
+                other_head.borrow_mut().prev = Some(self_tail);
+                self.tail = other.tail.take();
+            }
+            (Some(self_tail), None) => {
+                // other is empty, nothing to splice
+                self.tail = Some(self_tail);
+            }
+            (None, Some(other_head)) => {
+                // self is empty, take over other's chain wholesale
+                self.head = Some(other_head);
+                self.tail = other.tail.take();
+            }
+            (None, None) => {}
+        }
+        self.len += other.len;
+        other.tail = None;
+        other.len = 0;
+    }
+
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        let mut new_list = List::new();
+        if at == 0 {
+            std::mem::swap(self, &mut new_list);
+            return new_list;
+        }
+        if at >= self.len {
+            return new_list;
+        }
+
+        // Walk to the node at `at`; it becomes the head of the trailing list.
+        let mut current = self.head.clone();
+        for _ in 0..at {
+            current = current.unwrap().borrow().next.clone();
+        }
+        let node = current.unwrap();
+        let prev = node.borrow_mut().prev.take().unwrap();
+        prev.borrow_mut().next = None;
+
+        new_list.head = Some(node);
+        new_list.tail = self.tail.take();
+        new_list.len = self.len - at;
+        self.tail = Some(prev);
+        self.len = at;
+        new_list
+    }
+
+    pub fn remove_at(&mut self, index: usize) -> Option<T> {
+        let mut current = self.head.clone();
+        let mut i = 0;
+        while let Some(node) = current {
+            if i == index {
+                return Some(self.remove_node(node));
+            }
+            current = node.borrow().next.clone();
+            i += 1;
+        }
+        None
+    }
+}
+
+impl<T: PartialEq> List<T> {
+    pub fn contains(&self, value: &T) -> bool {
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            if node.borrow().elem == *value {
+                return true;
+            }
+            current = node.borrow().next.clone();
+        }
+        false
+    }
+
+    pub fn remove(&mut self, value: &T) -> bool {
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            if node.borrow().elem == *value {
+                self.remove_node(node);
+                return true;
+            }
+            current = node.borrow().next.clone();
+        }
+        false
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -165,6 +314,565 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
     }
 }
 
+/// A by-reference iterator over a [`List`].
+///
+/// Unlike the standard [`Iterator`], `next`/`next_back` are inherent methods
+/// that hand back a [`Ref`] guard borrowed from the iterator itself. As the
+/// Too Many Lists book explains, you cannot conjure a plain `&T` out of a
+/// `RefCell`, so the guard is the honest return type — and because the guard
+/// borrows from `self`, the standard `Iterator`/`DoubleEndedIterator` traits
+/// (whose `Item` may not borrow the iterator) cannot be implemented.
+///
+/// The `'a` lifetime ties the iterator to the borrow of the list, and
+/// `remaining` tracks how many nodes lie between the two ends. The currently
+/// borrowed node lives in `stash`; every step overwrites it (releasing the
+/// previous clone) and exhaustion clears both ends, so a completed walk holds
+/// no `Rc` the list still owns — preserving the single-owner invariant `pop`
+/// relies on.
+pub struct Iter<'a, T> {
+    next: Link<T>,
+    next_back: Link<T>,
+    remaining: usize,
+    stash: Link<T>,
+    _marker: PhantomData<&'a List<T>>
+}
+
+/// A by-mutable-reference iterator over a [`List`], yielding [`RefMut`] guards.
+///
+/// See [`Iter`] for why the guard types are the honest signature, why this is a
+/// lending iterator rather than an [`Iterator`] impl, and how the node clones
+/// are released as it advances.
+pub struct IterMut<'a, T> {
+    next: Link<T>,
+    next_back: Link<T>,
+    remaining: usize,
+    stash: Link<T>,
+    _marker: PhantomData<&'a mut List<T>>
+}
+
+impl<T> List<T> {
+    /// Returns a forward/backward iterator yielding [`Ref`] guards.
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            next: self.head.clone(),
+            next_back: self.tail.clone(),
+            remaining: self.len,
+            stash: None,
+            _marker: PhantomData
+        }
+    }
+
+    /// Returns a forward/backward iterator yielding [`RefMut`] guards.
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        IterMut {
+            next: self.head.clone(),
+            next_back: self.tail.clone(),
+            remaining: self.len,
+            stash: None,
+            _marker: PhantomData
+        }
+    }
+}
+
+impl<T> Iter<'_, T> {
+    /// Advances from the head, yielding a guard onto the next element.
+    pub fn next(&mut self) -> Option<Ref<'_, T>> {
+        if self.remaining == 0 {
+            self.stash = None;
+            return None;
+        }
+        let node = self.next.take().unwrap();
+        self.remaining -= 1;
+        self.next = node.borrow().next.clone();
+        if self.remaining == 0 {
+            // The two ends have met; drop the far-end handle as well.
+            self.next = None;
+            self.next_back = None;
+        }
+        self.stash = Some(node);
+        Some(Ref::map(self.stash.as_ref().unwrap().borrow(), |node| &node.elem))
+    }
+
+    /// Advances from the tail, yielding a guard onto the previous element.
+    pub fn next_back(&mut self) -> Option<Ref<'_, T>> {
+        if self.remaining == 0 {
+            self.stash = None;
+            return None;
+        }
+        let node = self.next_back.take().unwrap();
+        self.remaining -= 1;
+        self.next_back = node.borrow().prev.clone();
+        if self.remaining == 0 {
+            self.next = None;
+            self.next_back = None;
+        }
+        self.stash = Some(node);
+        Some(Ref::map(self.stash.as_ref().unwrap().borrow(), |node| &node.elem))
+    }
+}
+
+impl<T> IterMut<'_, T> {
+    /// Advances from the head, yielding a mutable guard onto the next element.
+    pub fn next(&mut self) -> Option<RefMut<'_, T>> {
+        if self.remaining == 0 {
+            self.stash = None;
+            return None;
+        }
+        let node = self.next.take().unwrap();
+        self.remaining -= 1;
+        self.next = node.borrow().next.clone();
+        if self.remaining == 0 {
+            self.next = None;
+            self.next_back = None;
+        }
+        self.stash = Some(node);
+        Some(RefMut::map(self.stash.as_ref().unwrap().borrow_mut(), |node| &mut node.elem))
+    }
+
+    /// Advances from the tail, yielding a mutable guard onto the previous element.
+    pub fn next_back(&mut self) -> Option<RefMut<'_, T>> {
+        if self.remaining == 0 {
+            self.stash = None;
+            return None;
+        }
+        let node = self.next_back.take().unwrap();
+        self.remaining -= 1;
+        self.next_back = node.borrow().prev.clone();
+        if self.remaining == 0 {
+            self.next = None;
+            self.next_back = None;
+        }
+        self.stash = Some(node);
+        Some(RefMut::map(self.stash.as_ref().unwrap().borrow_mut(), |node| &mut node.elem))
+    }
+}
+
+/// A read-only cursor over a [`List`].
+///
+/// The cursor either points at one of the list's nodes or sits on the
+/// conceptual "ghost" position (a null slot between the tail and the head).
+/// `move_next`/`move_prev` wrap around through the ghost, so walking off either
+/// end lands you on it rather than getting stuck.
+pub struct Cursor<'a, T> {
+    cur: Link<T>,
+    list: &'a List<T>
+}
+
+/// A cursor that can splice into and out of a [`List`] while walking it.
+///
+/// Like [`Cursor`] it may sit on the ghost position; the mutating operations
+/// treat the ghost as the boundary between the tail and the head, so inserting
+/// "before" the ghost pushes onto the back and inserting "after" it pushes onto
+/// the front.
+pub struct CursorMut<'a, T> {
+    cur: Link<T>,
+    list: &'a mut List<T>
+}
+
+impl<T> List<T> {
+    /// Returns a cursor sitting on the ghost position.
+    pub fn cursor(&self) -> Cursor<T> {
+        Cursor { cur: None, list: self }
+    }
+
+    /// Returns a mutable cursor sitting on the ghost position.
+    pub fn cursor_mut(&mut self) -> CursorMut<T> {
+        CursorMut { cur: None, list: self }
+    }
+}
+
+impl<T> Cursor<'_, T> {
+    /// Advances one node towards the tail, wrapping through the ghost.
+    pub fn move_next(&mut self) {
+        match self.cur.take() {
+            Some(node) => self.cur = node.borrow().next.clone(),
+            None => self.cur = self.list.head.clone()
+        }
+    }
+
+    /// Steps one node back towards the head, wrapping through the ghost.
+    pub fn move_prev(&mut self) {
+        match self.cur.take() {
+            Some(node) => self.cur = node.borrow().prev.clone(),
+            None => self.cur = self.list.tail.clone()
+        }
+    }
+
+    /// Borrows the element the cursor currently points at, if any.
+    pub fn current(&self) -> Option<Ref<T>> {
+        self.cur.as_ref().map(|node| Ref::map(node.borrow(), |node| &node.elem))
+    }
+}
+
+impl<T: Clone> Cursor<'_, T> {
+    /// Clones the element that [`move_next`](Self::move_next) would land on.
+    ///
+    /// A `Ref` guard cannot be handed out here: the neighbour lives behind a
+    /// different `RefCell` than the node the cursor owns, so the honest thing to
+    /// return through the shared reference is an owned clone.
+    pub fn peek_next(&self) -> Option<T> {
+        let next = match &self.cur {
+            Some(node) => node.borrow().next.clone(),
+            None => self.list.head.clone()
+        };
+        next.map(|node| node.borrow().elem.clone())
+    }
+
+    /// Clones the element that [`move_prev`](Self::move_prev) would land on.
+    pub fn peek_prev(&self) -> Option<T> {
+        let prev = match &self.cur {
+            Some(node) => node.borrow().prev.clone(),
+            None => self.list.tail.clone()
+        };
+        prev.map(|node| node.borrow().elem.clone())
+    }
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Advances one node towards the tail, wrapping through the ghost.
+    pub fn move_next(&mut self) {
+        match self.cur.take() {
+            Some(node) => self.cur = node.borrow().next.clone(),
+            None => self.cur = self.list.head.clone()
+        }
+    }
+
+    /// Steps one node back towards the head, wrapping through the ghost.
+    pub fn move_prev(&mut self) {
+        match self.cur.take() {
+            Some(node) => self.cur = node.borrow().prev.clone(),
+            None => self.cur = self.list.tail.clone()
+        }
+    }
+
+    /// Mutably borrows the element the cursor currently points at, if any.
+    pub fn current(&mut self) -> Option<RefMut<T>> {
+        self.cur.as_ref().map(|node| RefMut::map(node.borrow_mut(), |node| &mut node.elem))
+    }
+
+    /// Inserts `elem` between the current node and its successor.
+    ///
+    /// On the ghost position this prepends onto the head.
+    pub fn insert_after(&mut self, elem: T) {
+        match &self.cur {
+            Some(cur) => {
+                let new_node = Node::new(elem);
+                let next = cur.borrow_mut().next.take();
+                match next {
+                    Some(next) => {
+                        next.borrow_mut().prev = Some(new_node.clone());
+                        new_node.borrow_mut().next = Some(next);
+                    }
+                    None => self.list.tail = Some(new_node.clone())
+                }
+                new_node.borrow_mut().prev = Some(cur.clone());
+                cur.borrow_mut().next = Some(new_node);
+                self.list.len += 1;
+            }
+            None => self.list.push_front(elem)
+        }
+    }
+
+    /// Inserts `elem` between the current node and its predecessor.
+    ///
+    /// On the ghost position this appends onto the tail.
+    pub fn insert_before(&mut self, elem: T) {
+        match &self.cur {
+            Some(cur) => {
+                let new_node = Node::new(elem);
+                let prev = cur.borrow_mut().prev.take();
+                match prev {
+                    Some(prev) => {
+                        prev.borrow_mut().next = Some(new_node.clone());
+                        new_node.borrow_mut().prev = Some(prev);
+                    }
+                    None => self.list.head = Some(new_node.clone())
+                }
+                new_node.borrow_mut().next = Some(cur.clone());
+                cur.borrow_mut().prev = Some(new_node);
+                self.list.len += 1;
+            }
+            None => self.list.push_back(elem)
+        }
+    }
+
+    /// Removes the current node, returns its element, and advances the cursor
+    /// onto the following node (or the ghost if it was the tail).
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.cur.take().map(|cur| {
+            let prev = cur.borrow_mut().prev.take();
+            let next = cur.borrow_mut().next.take();
+            match (prev, next) {
+                (Some(prev), Some(next)) => {
+                    prev.borrow_mut().next = Some(next.clone());
+                    next.borrow_mut().prev = Some(prev);
+                    self.cur = Some(next);
+                }
+                (Some(prev), None) => {
+                    prev.borrow_mut().next = None;
+                    self.list.tail = Some(prev);
+                    self.cur = None;
+                }
+                (None, Some(next)) => {
+                    next.borrow_mut().prev = None;
+                    self.list.head = Some(next.clone());
+                    self.cur = Some(next);
+                }
+                (None, None) => {
+                    self.list.head = None;
+                    self.list.tail = None;
+                    self.cur = None;
+                }
+            }
+            self.list.len -= 1;
+            Rc::try_unwrap(cur).ok().unwrap().into_inner().elem
+        })
+    }
+
+    /// Splits the list just after the current node, returning everything from
+    /// the successor to the tail as a new list and leaving the head-side here.
+    ///
+    /// The cursor is left on the ghost position: we take its retained `Rc` out
+    /// and hand it straight to the list, so the list stays the single owner of
+    /// the node the caller can then safely `pop`/`remove`.
+    ///
+    /// On the ghost position the whole list is handed back.
+    pub fn split_after(&mut self) -> List<T> {
+        match self.cur.take() {
+            Some(cur) => {
+                let next = cur.borrow_mut().next.take();
+                match next {
+                    Some(next) => {
+                        next.borrow_mut().prev = None;
+                        let mut new_list = List::new();
+                        new_list.tail = self.list.tail.take();
+                        new_list.head = Some(next);
+                        new_list.len = count_nodes(&new_list.head);
+                        self.list.tail = Some(cur);
+                        self.list.len -= new_list.len;
+                        new_list
+                    }
+                    None => List::new()
+                }
+            }
+            None => std::mem::take(self.list)
+        }
+    }
+
+    /// Splits the list just before the current node, returning everything from
+    /// the head up to the predecessor as a new list and leaving the tail-side
+    /// here.
+    ///
+    /// As in [`split_after`](Self::split_after) the cursor is left on the ghost
+    /// position and its retained `Rc` handed back to the list.
+    ///
+    /// On the ghost position the whole list is handed back.
+    pub fn split_before(&mut self) -> List<T> {
+        match self.cur.take() {
+            Some(cur) => {
+                let prev = cur.borrow_mut().prev.take();
+                match prev {
+                    Some(prev) => {
+                        prev.borrow_mut().next = None;
+                        let mut new_list = List::new();
+                        new_list.head = self.list.head.take();
+                        new_list.tail = Some(prev);
+                        new_list.len = count_nodes(&new_list.head);
+                        self.list.head = Some(cur);
+                        self.list.len -= new_list.len;
+                        new_list
+                    }
+                    None => List::new()
+                }
+            }
+            None => std::mem::take(self.list)
+        }
+    }
+}
+
+impl<T: Clone> CursorMut<'_, T> {
+    /// Clones the element that [`move_next`](Self::move_next) would land on.
+    ///
+    /// As with [`Cursor`] a `RefMut` guard cannot escape here, so the element
+    /// is returned by clone.
+    pub fn peek_next(&self) -> Option<T> {
+        let next = match &self.cur {
+            Some(node) => node.borrow().next.clone(),
+            None => self.list.head.clone()
+        };
+        next.map(|node| node.borrow().elem.clone())
+    }
+
+    /// Clones the element that [`move_prev`](Self::move_prev) would land on.
+    pub fn peek_prev(&self) -> Option<T> {
+        let prev = match &self.cur {
+            Some(node) => node.borrow().prev.clone(),
+            None => self.list.tail.clone()
+        };
+        prev.map(|node| node.borrow().elem.clone())
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self)
+    }
+}
+
+// The `RefCell` backend cannot hand out bare `&T`/`&mut T` through a standard
+// iterator (see `Iter`), so the by-reference `IntoIterator` forms deep-copy the
+// list and iterate by value. They exist so `List<T>` slots into generic code
+// that writes `for x in &list`.
+impl<T: Clone> IntoIterator for &List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self.clone())
+    }
+}
+
+impl<T: Clone> IntoIterator for &mut List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter(self.clone())
+    }
+}
+
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        // Deep copy: the clone walks the chain and re-pushes, so it shares no
+        // `Rc` with the original.
+        let mut new = List::new();
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            new.push_back(node.borrow().elem.clone());
+            current = node.borrow().next.clone();
+        }
+        new
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        let mut a = self.head.clone();
+        let mut b = other.head.clone();
+        loop {
+            match (a, b) {
+                (Some(x), Some(y)) => {
+                    if x.borrow().elem != y.borrow().elem {
+                        return false;
+                    }
+                    a = x.borrow().next.clone();
+                    b = y.borrow().next.clone();
+                }
+                (None, None) => return true,
+                _ => return false
+            }
+        }
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: PartialOrd> PartialOrd for List<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let mut a = self.head.clone();
+        let mut b = other.head.clone();
+        loop {
+            match (a, b) {
+                (Some(x), Some(y)) => {
+                    match x.borrow().elem.partial_cmp(&y.borrow().elem) {
+                        Some(Ordering::Equal) => {}
+                        non_eq => return non_eq
+                    }
+                    a = x.borrow().next.clone();
+                    b = y.borrow().next.clone();
+                }
+                (None, None) => return Some(Ordering::Equal),
+                (None, _) => return Some(Ordering::Less),
+                (_, None) => return Some(Ordering::Greater)
+            }
+        }
+    }
+}
+
+impl<T: Ord> Ord for List<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut a = self.head.clone();
+        let mut b = other.head.clone();
+        loop {
+            match (a, b) {
+                (Some(x), Some(y)) => {
+                    match x.borrow().elem.cmp(&y.borrow().elem) {
+                        Ordering::Equal => {}
+                        non_eq => return non_eq
+                    }
+                    a = x.borrow().next.clone();
+                    b = y.borrow().next.clone();
+                }
+                (None, None) => return Ordering::Equal,
+                (None, _) => return Ordering::Less,
+                (_, None) => return Ordering::Greater
+            }
+        }
+    }
+}
+
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Count first so the length is folded in like the standard collections.
+        let mut count = 0usize;
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            count += 1;
+            current = node.borrow().next.clone();
+        }
+        count.hash(state);
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            node.borrow().elem.hash(state);
+            current = node.borrow().next.clone();
+        }
+    }
+}
+
+impl<T: Debug> Debug for List<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut dl = f.debug_list();
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            dl.entry(&node.borrow().elem);
+            current = node.borrow().next.clone();
+        }
+        dl.finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::ops::DerefMut;
@@ -236,4 +944,142 @@ mod test {
         assert_eq!(iter.next(), Some(2));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn traits() {
+        let list: List<i32> = [1, 2, 3].into_iter().collect();
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+
+        // Clone is a deep copy and compares equal.
+        let clone = list.clone();
+        assert_eq!(list, clone);
+
+        // Default + Extend rebuild the same sequence.
+        let mut other = List::default();
+        other.extend([1, 2, 3]);
+        assert_eq!(list, other);
+
+        // Lexicographic ordering.
+        let bigger: List<i32> = [1, 2, 4].into_iter().collect();
+        assert!(list < bigger);
+    }
+
+    #[test]
+    fn remove_and_contains() {
+        let mut list = List::new();
+        list.push_back(1); list.push_back(2); list.push_back(3); list.push_back(4);
+
+        assert!(list.contains(&3));
+        assert!(!list.contains(&9));
+
+        // Pull a middle element out and check the chain stays intact.
+        assert!(list.remove(&3));
+        assert!(!list.contains(&3));
+        assert!(!list.remove(&3));
+
+        // remove_at covers head and the new tail.
+        assert_eq!(list.remove_at(0), Some(1));
+        assert_eq!(list.remove_at(1), Some(4));
+        assert_eq!(list.remove_at(5), None);
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = List::new();
+        list.push_back(1); list.push_back(2); list.push_back(3);
+
+        let mut sum = 0;
+        let mut iter = list.iter();
+        while let Some(elem) = iter.next() {
+            sum += *elem;
+        }
+        assert_eq!(sum, 6);
+
+        // Walking from the back visits the same elements in reverse.
+        let mut iter = list.iter();
+        assert_eq!(*iter.next_back().unwrap(), 3);
+        assert_eq!(*iter.next().unwrap(), 1);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = List::new();
+        list.push_back(1); list.push_back(2); list.push_back(3);
+
+        let mut iter = list.iter_mut();
+        while let Some(mut elem) = iter.next() {
+            *elem *= 10;
+        }
+
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.pop_front(), Some(20));
+        assert_eq!(list.pop_front(), Some(30));
+    }
+
+    #[test]
+    fn append_and_split() {
+        // Append a non-empty list onto an empty one.
+        let mut list = List::new();
+        let mut other = List::new();
+        other.push_back(1); other.push_back(2); other.push_back(3);
+        list.append(&mut other);
+        assert_eq!(list.len(), 3);
+        assert!(other.is_empty());
+
+        // Append more onto the now-populated list.
+        let mut tail = List::new();
+        tail.push_back(4); tail.push_back(5);
+        list.append(&mut tail);
+        assert_eq!(list.len(), 5);
+
+        // Split at len yields an empty trailing list, leaving everything here.
+        let empty = list.split_off(list.len());
+        assert!(empty.is_empty());
+        assert_eq!(list.len(), 5);
+
+        // Split in the middle.
+        let mut back = list.split_off(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(back.len(), 2);
+        assert_eq!(back.pop_front(), Some(4));
+        assert_eq!(back.pop_front(), Some(5));
+
+        // Split at 0 moves the whole list.
+        let mut whole = list.split_off(0);
+        assert_eq!(list.len(), 0);
+        assert_eq!(whole.len(), 3);
+        assert_eq!(whole.pop_front(), Some(1));
+    }
+
+    #[test]
+    fn cursor_mut() {
+        let mut list = List::new();
+        list.push_back(1); list.push_back(2); list.push_back(4);
+
+        // Scope the cursor so it (and its retained node handle) is dropped
+        // before we mutate the list directly below.
+        let mut front = {
+            let mut cursor = list.cursor_mut();
+            cursor.move_next();          // on 1
+            cursor.move_next();          // on 2
+            cursor.insert_after(3);      // 1 2 3 4
+            assert_eq!(cursor.peek_next(), Some(3));
+            cursor.move_next();          // on 3
+            assert_eq!(&*cursor.current().unwrap(), &3);
+
+            // Pull the 3 back out; the cursor should land on 4.
+            assert_eq!(cursor.remove_current(), Some(3));
+            assert_eq!(&*cursor.current().unwrap(), &4);
+
+            // Sever the head-side (1 2); the list keeps the current node onward (4).
+            cursor.split_before()
+        };
+        assert_eq!(list.pop_front(), Some(4));
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(front.pop_front(), Some(1));
+        assert_eq!(front.pop_front(), Some(2));
+        assert_eq!(front.pop_front(), None);
+    }
 }